@@ -0,0 +1,49 @@
+// two ways to assemble a sentence out of parts: one that takes
+// ownership of each piece, one that only borrows them.
+
+// consumes parts: each String is moved out of the Vec, its bytes are
+// copied into the result, and it is then dropped, so the Vec's elements
+// are no longer usable afterward (the Vec itself is too, since iterating
+// it by value moves it).
+pub fn join_owned(parts: Vec<String>) -> String {
+    let mut result = String::new();
+    for (i, part) in parts.into_iter().enumerate() {
+        if i > 0 {
+            result.push(' ');
+        }
+        result.push_str(&part); // part is dropped at the end of this iteration
+    }
+    result
+}
+
+// borrows parts: each &str is only read, so the source slices are still
+// valid after this returns.
+pub fn join_borrowed(parts: &[&str]) -> String {
+    let mut result = String::new();
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            result.push(' ');
+        }
+        result.push_str(part);
+    }
+    result
+}
+
+// shows the Vec<String> being unusable after join_owned, while the
+// source slices behind join_borrowed remain valid.
+pub fn demo() {
+    let owned_parts = vec![
+        String::from("the"),
+        String::from("quick"),
+        String::from("fox"),
+    ];
+    let sentence = join_owned(owned_parts);
+    println!("join_owned => {}", sentence);
+    // println!("{:?}", owned_parts); // WON'T COMPILE: moved into join_owned
+
+    let borrowed_parts = ["the", "lazy", "dog"];
+    let sentence = join_borrowed(&borrowed_parts);
+    println!("join_borrowed => {}", sentence);
+    // borrowed_parts is still valid here; join_borrowed only read from it.
+    println!("borrowed_parts still usable => {:?}", borrowed_parts);
+}
@@ -0,0 +1,9 @@
+// exploring ownership and references in Rust
+// Travis Perdue
+// taken almost verbatum from
+// https://doc.rust-lang.org/book/second-edition/ch04-01-what-is-ownership.html
+
+pub mod concat;
+pub mod mem_report;
+pub mod resource;
+pub mod strings;
@@ -1,7 +1,12 @@
 // exploring ownership and references in Rust
 // Travis Perdue
 // taken almost verbatum from
-// https://doc.rust-lang.org/book/second-edition/ch04-01-what-is-ownership.html 
+// https://doc.rust-lang.org/book/second-edition/ch04-01-what-is-ownership.html
+
+use rust_ownership::strings::{
+    calculate_len_with_ref, calculate_length, change, first_word, gives_ownership, last_word,
+    makes_copy, nth_word, takes_and_gives_back, takes_ownership, words,
+};
 
 fn main() {
     // EXPLORE OWNERSHIP BASICS
@@ -22,8 +27,8 @@ fn main() {
         println!("{}", s); // "hello, world."
     } // Rust calls Drop here when scope is gone to free memory
 
-    // bind 5 to x  
-    let x = 5; 
+    // bind 5 to x
+    let x = 5;
     // make a copy
     let y = x;
     // x isn't invalidated because int's size is known at compile time
@@ -61,7 +66,7 @@ fn main() {
                                     // use x afterward.
 
 
-    // println!("{}", s); // WON'T WORK 
+    // println!("{}", s); // WON'T WORK
     println!("{}", x); // Copied value. will work
 
 
@@ -159,61 +164,38 @@ fn main() {
     println!("first word => {}", word);
 
     // s.clear(); // ERROR. because first_word references s, clearing s causes
-    // compile time error. 
+    // compile time error.
+
+    // first_word only finds the leading word. words/last_word/nth_word
+    // generalize that to the whole string, still just borrowing slices.
+    let sentence = "the quick brown\tfox\njumps";
+    println!("words => {:?}", words(sentence));
+    println!("last_word => {:?}", last_word(sentence));
+    println!("nth_word(2) => {:?}", nth_word(sentence, 2));
 
     // another type of slice
     let a = [1,2,3,4,5];
     let slice = &a[0..2];
+
+    // EXPLORE STACK VS HEAP, FOR REAL
+    // the comments above narrate "on the heap" / "on the stack" -- this
+    // demo prints size_of_val, len/capacity, and heap addresses so that's
+    // observable instead of asserted.
+    rust_ownership::mem_report::demo();
+
+    // EXPLORE DROP
+    // watch the "dropping: {name}" prints to see scope-based cleanup,
+    // drop-on-move, and independent drops after a clone.
+    rust_ownership::resource::demo();
+
+    // EXPLORE MOVE VS BORROW IN STRING BUILDING
+    // join_owned consumes its Vec<String>; join_borrowed only reads its
+    // &[&str], so the source slices are still valid afterward.
+    rust_ownership::concat::demo();
 }   // from EXPLORE OWNERSHIP BASICS section
     // Here, s3 goes out of scope and is dropped. s2 goes out of scope but was
     // moved, so nothing happens. s1 goes out of scope and is dropped.
 
-// direct from the book (just great explaining comments)
-fn takes_ownership(some_string: String) { // some_string comes into scope.
-    println!("{}", some_string);
-} // Here, some_string goes out of scope and `drop` is called. The backing
-  // memory is freed.
-
-fn makes_copy(some_integer: i32) { // some_integer comes into scope.
-    println!("{}", some_integer);
-} // Here, some_integer goes out of scope. Nothing special happens.
-
-fn gives_ownership() -> String {            // gives_ownership will move its
-                                            // return value into the function
-                                            // that calls it.
-
-    let some_string = String::from("hello");    // some_string comes into scope.
-
-    some_string                                 // some_string is returned and
-                                                // moves out to the calling
-                                                // function.
-}
-
-// takes_and_gives_back will take a String and return one.
-fn takes_and_gives_back(a_string: String) -> String {   // a_string comes into
-                                                        // scope
-    a_string  // a_string is returned and moves out to the calling function.
-}
-
-fn calculate_length(string: String) -> (String, usize) {
-    let length = string.len();
-    (string, length)
-}
-
-// having references as func params is called BORROWING
-fn calculate_len_with_ref(string: &String) -> usize {
-    string.len()
-}   // string goes out of scope but is a reference so nothing happens to what
-    // it points to
-
-
-// borrowed values can't be modified by default
-// must be a mutable value and be passed to func
-// as a mutable ref
-fn change(string: &mut String) {
-    string.push_str(", world");
-}
-
 // this will break if attempted to compile
 // fn dangler() -> &String {   // dangle returns a reference to a String
 //     let s = String::from("heyo");   // s is a new String
@@ -221,30 +203,3 @@ fn change(string: &mut String) {
 // }       // Here, s goes out of scope, and is dropped. Its memory goes away.
         // the reference to s now points to null and breaks
         // Rust compiler stops us from this
-
-
-fn first_word(string: &str) -> &str {
-    let bytes = string.as_bytes();
-
-    // variables for finding first char in string
-    // while ignoring leading whitespace.
-    let mut char_found = false;
-    let mut first_char: usize = 0;
-
-    for(i, &item) in bytes.iter().enumerate() {
-        // first char found after any leading white space
-        if !char_found && !(item == b' ') {
-            char_found = true;
-            first_char = i;
-        }
-        // first space after first word ignoring any
-        // leading white space
-        else if item == b' ' && char_found {
-            return &string[first_char..i];
-        } // end if/else if
-    } // end for
-    // entire string has no whitespace
-    &string[..]
-} // end first_word
-
-
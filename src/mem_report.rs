@@ -0,0 +1,63 @@
+// size_of_val reports what a value itself costs on the stack; len()
+// and capacity() on String/Vec report what's sitting in their separate
+// heap buffer. The two numbers move independently, which is the point:
+// growing the heap buffer doesn't change the stack-side size at all.
+
+// prints label's stack footprint via size_of_val. this is the size of
+// value itself (e.g. a String's pointer/len/cap triple), not the heap
+// buffer it may point to -- see describe_string/describe_vec for that.
+pub fn describe<T>(label: &str, value: &T) {
+    println!(
+        "{}: {} bytes on the stack",
+        label,
+        std::mem::size_of_val(value)
+    );
+}
+
+// like describe, but also reports the heap-side len/capacity and the
+// heap pointer s owns.
+pub fn describe_string(label: &str, s: &String) {
+    describe(label, s);
+    println!(
+        "{}: len = {}, capacity = {}, heap ptr = {:p}",
+        label,
+        s.len(),
+        s.capacity(),
+        s.as_ptr()
+    );
+}
+
+// like describe, but also reports the heap-side len/capacity and the
+// heap pointer v owns.
+pub fn describe_vec<T>(label: &str, v: &Vec<T>) {
+    describe(label, v);
+    println!(
+        "{}: len = {}, capacity = {}, heap ptr = {:p}",
+        label,
+        v.len(),
+        v.capacity(),
+        v.as_ptr()
+    );
+}
+
+// shows a Copy i32 never touching the heap, contrasted with a String
+// and a Vec whose capacity jumps once push_str/push outgrows the
+// buffer they started with. The heap pointer is not a reliable signal
+// here -- the allocator is free to grow a buffer in place -- so the
+// capacity numbers are what actually demonstrate the reallocation.
+pub fn demo() {
+    let x: i32 = 5;
+    describe("x", &x); // always 4 bytes, no heap pointer to report
+
+    let mut s = String::from("hi");
+    describe_string("s before push_str", &s);
+    s.push_str(", this is long enough to force a reallocation");
+    describe_string("s after push_str", &s);
+
+    let mut v: Vec<i32> = Vec::with_capacity(2);
+    describe_vec("v before push", &v);
+    v.push(1);
+    v.push(2);
+    v.push(3); // exceeds the starting capacity of 2
+    describe_vec("v after push", &v);
+}
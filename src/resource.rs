@@ -0,0 +1,66 @@
+// a resource that announces its own creation, drop, and clone so scope
+// exit, move-into-a-function, and clone independence each print
+// something instead of having to be taken on faith.
+
+pub struct TrackedResource {
+    name: String,
+}
+
+impl TrackedResource {
+    pub fn new(name: &str) -> TrackedResource {
+        println!("creating: {}", name);
+        TrackedResource {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Drop for TrackedResource {
+    fn drop(&mut self) {
+        println!("dropping: {}", self.name);
+    }
+}
+
+impl Clone for TrackedResource {
+    fn clone(&self) -> TrackedResource {
+        // a clone is a fully independent resource -- it gets dropped on
+        // its own, separately from the original.
+        let cloned_name = format!("{} (clone)", self.name);
+        println!("cloning: {} -> {}", self.name, cloned_name);
+        TrackedResource {
+            name: cloned_name,
+        }
+    }
+}
+
+fn takes_ownership(resource: TrackedResource) {
+    // resource is moved in; it drops here, inside the callee, once this
+    // function returns -- not back in the caller's scope.
+    println!("inside takes_ownership with: {}", resource.name);
+}
+
+// shows drop order in nested scopes, a move into a function dropping the
+// resource in the callee rather than the caller, and a clone dropping
+// independently of the original.
+pub fn demo() {
+    let outer = TrackedResource::new("outer");
+    {
+        let inner = TrackedResource::new("inner");
+        println!("inner scope still has: {}", inner.name);
+    } // inner drops here, before outer
+    println!("outer scope still has: {}", outer.name);
+
+    let moved = TrackedResource::new("moved");
+    takes_ownership(moved);
+    // moved was moved into takes_ownership and already dropped there;
+    // using it here would not compile.
+
+    let original = TrackedResource::new("original");
+    let cloned = original.clone();
+    println!(
+        "original: {}, cloned: {} -- two independent resources",
+        original.name, cloned.name
+    );
+    // cloned drops first (reverse declaration order), then original,
+    // then outer when this function returns.
+}
@@ -0,0 +1,178 @@
+// move/clone/borrow helpers and the whitespace-aware word tokenizer.
+// pulled out of main.rs so the ownership lessons can be reused and tested
+// instead of only being demonstrated once inline.
+
+// direct from the book (just great explaining comments)
+pub fn takes_ownership(some_string: String) { // some_string comes into scope.
+    println!("{}", some_string);
+} // Here, some_string goes out of scope and `drop` is called. The backing
+  // memory is freed.
+
+pub fn makes_copy(some_integer: i32) { // some_integer comes into scope.
+    println!("{}", some_integer);
+} // Here, some_integer goes out of scope. Nothing special happens.
+
+pub fn gives_ownership() -> String {        // gives_ownership will move its
+                                            // return value into the function
+                                            // that calls it.
+
+    let some_string = String::from("hello");    // some_string comes into scope.
+
+    some_string                                 // some_string is returned and
+                                                // moves out to the calling
+                                                // function.
+}
+
+// takes_and_gives_back will take a String and return one.
+pub fn takes_and_gives_back(a_string: String) -> String {   // a_string comes into
+                                                        // scope
+    a_string  // a_string is returned and moves out to the calling function.
+}
+
+pub fn calculate_length(string: String) -> (String, usize) {
+    let length = string.len();
+    (string, length)
+}
+
+// having references as func params is called BORROWING
+pub fn calculate_len_with_ref(string: &str) -> usize {
+    string.len()
+}   // string goes out of scope but is a reference so nothing happens to what
+    // it points to
+
+// borrowed values can't be modified by default
+// must be a mutable value and be passed to func
+// as a mutable ref
+pub fn change(string: &mut String) {
+    string.push_str(", world");
+}
+
+pub fn first_word(string: &str) -> &str {
+    let bytes = string.as_bytes();
+
+    // variables for finding first char in string
+    // while ignoring leading whitespace.
+    let mut char_found = false;
+    let mut first_char: usize = 0;
+
+    for(i, &item) in bytes.iter().enumerate() {
+        // first char found after any leading white space
+        if !char_found && item != b' ' {
+            char_found = true;
+            first_char = i;
+        }
+        // first space after first word ignoring any
+        // leading white space
+        else if item == b' ' && char_found {
+            return &string[first_char..i];
+        } // end if/else if
+    } // end for
+    // entire string has no whitespace
+    string
+} // end first_word
+
+// first_word above only catches a single leading word and only treats
+// b' ' as a separator, so tabs/newlines get pulled into the word and
+// anything past the first space is lost. WordIter below walks
+// char_indices() so multi-byte UTF-8 never gets split mid-codepoint,
+// and yields every whitespace-delimited slice lazily.
+pub struct WordIter<'a> {
+    source: &'a str,
+    // byte index where the word currently being scanned started,
+    // or None when we're between words
+    start: Option<usize>,
+    chars: std::str::CharIndices<'a>,
+}
+
+impl<'a> WordIter<'a> {
+    pub fn new(source: &'a str) -> WordIter<'a> {
+        WordIter {
+            source,
+            start: None,
+            chars: source.char_indices(),
+        }
+    }
+}
+
+impl<'a> Iterator for WordIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        for (i, c) in self.chars.by_ref() {
+            if c.is_whitespace() {
+                if let Some(i0) = self.start.take() {
+                    return Some(&self.source[i0..i]);
+                }
+                // still between words, keep going
+            } else if self.start.is_none() {
+                self.start = Some(i);
+            }
+        }
+        // ran out of chars; emit the trailing word if we were in one
+        self.start.take().map(|i0| &self.source[i0..])
+    }
+}
+
+// every whitespace-delimited slice in `s`, in order
+pub fn words(s: &str) -> Vec<&str> {
+    WordIter::new(s).collect()
+}
+
+// last whitespace-delimited slice in `s`, or None if `s` has no words
+pub fn last_word(s: &str) -> Option<&str> {
+    WordIter::new(s).last()
+}
+
+// the nth (0-indexed) whitespace-delimited slice in `s`, or None if
+// there aren't that many words
+pub fn nth_word(s: &str, n: usize) -> Option<&str> {
+    WordIter::new(s).nth(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_empty_string() {
+        assert_eq!(words(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn words_all_whitespace() {
+        assert_eq!(words("   \t\n  "), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn words_leading_and_trailing_whitespace() {
+        assert_eq!(words("  hello world  "), vec!["hello", "world"]);
+    }
+
+    #[test]
+    fn words_single_word_no_spaces() {
+        assert_eq!(words("hello"), vec!["hello"]);
+    }
+
+    #[test]
+    fn last_word_returns_final_slice() {
+        assert_eq!(last_word("the quick brown fox"), Some("fox"));
+        assert_eq!(last_word(""), None);
+    }
+
+    #[test]
+    fn nth_word_indexes_from_zero() {
+        assert_eq!(nth_word("the quick brown fox", 0), Some("the"));
+        assert_eq!(nth_word("the quick brown fox", 2), Some("brown"));
+        assert_eq!(nth_word("the quick brown fox", 9), None);
+    }
+
+    #[test]
+    fn returned_slice_stays_valid_while_owner_lives() {
+        let owner = String::from("hello world");
+        let word = first_word(&owner);
+        // owner is still alive here, so the borrow checker lets us read
+        // both; `word` is just a slice into `owner`'s heap buffer.
+        assert_eq!(word, "hello");
+        assert_eq!(&owner[..5], word);
+    }
+}